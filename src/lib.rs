@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 type Handler = Option<JsValue>;
 type Params = HashMap<String, String>;
@@ -37,18 +38,33 @@ impl TreeNodeMut<'_> {
     }
 }
 
+/// A single radix edge: `label` is the compressed run of characters that
+/// must be consumed from the path to reach `node`. Two sibling edges never
+/// share a leading character, so a lookup only has to inspect at most one edge
+/// per node instead of hashing the whole segment.
+struct StaticEdge {
+    label: String,
+    node: StaticTreeNode,
+}
+
 #[derive(Default)]
 struct StaticTreeNode {
     handler: Handler,
     wildcard_handler: Handler,
-
-    static_children: HashMap<String, StaticTreeNode>,
-    dynamic_child: Option<Box<DynamicTreeNode>>,
+    wildcard_param_name: Option<String>,
+
+    static_children: Vec<StaticEdge>,
+    // More than one dynamic child can exist at the same position so long
+    // as each carries a distinct param name, e.g. `/users/:id` (int) and
+    // `/users/:name` (alpha) registered side by side - the constraint is
+    // what keeps them from being ambiguous at match time.
+    dynamic_children: Vec<Box<DynamicTreeNode>>,
 }
 
 struct DynamicTreeNode {
     node: StaticTreeNode,
     param_name: String,
+    constraint: Option<Constraint>,
 }
 
 impl DynamicTreeNode {
@@ -56,8 +72,68 @@ impl DynamicTreeNode {
         DynamicTreeNode {
             node: StaticTreeNode::new(handler),
             param_name: param_name.to_string(),
+            constraint: None,
         }
     }
+
+    pub fn set_constraint(&mut self, constraint: Option<Constraint>) {
+        self.constraint = constraint;
+    }
+}
+
+/// A check a dynamic segment's captured value must pass before it is
+/// bound, as registered through `add_with_constraints`. The built-in kinds
+/// cover the common cases from hyperbole-style routers; `Predicate` calls
+/// into a user-supplied JS function for anything more specific.
+enum Constraint {
+    Int,
+    Uuid,
+    Alpha,
+    Predicate(JsValue),
+}
+
+impl Constraint {
+    fn from_js(value: &JsValue) -> Option<Self> {
+        if let Some(kind) = value.as_string() {
+            match kind.as_str() {
+                "int" => Some(Constraint::Int),
+                "uuid" => Some(Constraint::Uuid),
+                "alpha" => Some(Constraint::Alpha),
+                _ => None,
+            }
+        } else if value.is_function() {
+            Some(Constraint::Predicate(value.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Constraint::Int => !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()),
+            Constraint::Alpha => {
+                !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphabetic())
+            }
+            Constraint::Uuid => Self::is_uuid(value),
+            Constraint::Predicate(function) => {
+                let function: &js_sys::Function = function.unchecked_ref();
+                function
+                    .call1(&JsValue::NULL, &JsValue::from_str(value))
+                    .map(|result| result.as_bool().unwrap_or(false))
+                    .unwrap_or(false)
+            }
+        }
+    }
+
+    fn is_uuid(value: &str) -> bool {
+        const GROUP_LENGTHS: [usize; 5] = [8, 4, 4, 4, 12];
+        let groups: Vec<&str> = value.split('-').collect();
+
+        groups.len() == GROUP_LENGTHS.len()
+            && groups.iter().zip(GROUP_LENGTHS).all(|(group, len)| {
+                group.len() == len && group.bytes().all(|b| b.is_ascii_hexdigit())
+            })
+    }
 }
 
 impl StaticTreeNode {
@@ -68,89 +144,259 @@ impl StaticTreeNode {
         }
     }
 
-    pub fn add_static_child(&mut self, segment: &str, handler: Handler) {
-        let child = StaticTreeNode::new(handler);
+    /// Byte offset of the longest shared prefix of `a` and `b`, clamped down
+    /// to the nearest char boundary so callers can always slice at it - two
+    /// labels can share a leading byte (e.g. the `0xC3` lead byte of `é` and
+    /// `è`) without sharing a whole codepoint.
+    fn longest_common_prefix(a: &str, b: &str) -> usize {
+        let shared_bytes = a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+
+        (0..=shared_bytes)
+            .rev()
+            .find(|&i| a.is_char_boundary(i))
+            .unwrap_or(0)
+    }
 
-        self.static_children.insert(segment.to_string(), child);
+    fn find_edge_index(&self, label: &str) -> Option<usize> {
+        let first_char = label.chars().next()?;
+        self.static_children
+            .iter()
+            .position(|edge| edge.label.chars().next() == Some(first_char))
     }
 
-    pub fn delete_static_child(&mut self, segment: &str) -> Option<StaticTreeNode> {
-        self.static_children.remove(segment)
+    /// Walks (and grows) the radix tree of static edges so that `label` is
+    /// reachable from `self`, splitting an existing edge when `label` only
+    /// partially matches it. Returns the node at the end of `label`.
+    pub fn insert_static_path(&mut self, label: &str) -> &mut StaticTreeNode {
+        if label.is_empty() {
+            return self;
+        }
+
+        let Some(index) = self.find_edge_index(label) else {
+            self.static_children.push(StaticEdge {
+                label: label.to_string(),
+                node: StaticTreeNode::new(None),
+            });
+            let last = self.static_children.len() - 1;
+            return &mut self.static_children[last].node;
+        };
+
+        let common = Self::longest_common_prefix(&self.static_children[index].label, label);
+
+        if common == self.static_children[index].label.len() {
+            return self.static_children[index]
+                .node
+                .insert_static_path(&label[common..]);
+        }
+
+        // The new label diverges partway through the existing edge: split
+        // it into a shared-prefix parent and two children for the
+        // divergent suffixes.
+        let mut old_edge = self.static_children.remove(index);
+        let old_suffix = old_edge.label[common..].to_string();
+        let shared_prefix = old_edge.label[..common].to_string();
+        old_edge.label = old_suffix;
+
+        let mut parent = StaticTreeNode::new(None);
+        parent.static_children.push(old_edge);
+
+        self.static_children.push(StaticEdge {
+            label: shared_prefix,
+            node: parent,
+        });
+        let index = self.static_children.len() - 1;
+
+        self.static_children[index]
+            .node
+            .insert_static_path(&label[common..])
+    }
+
+    pub fn delete_static_child(&mut self, label: &str) -> Option<StaticTreeNode> {
+        let index = self
+            .static_children
+            .iter()
+            .position(|edge| edge.label == label)?;
+        Some(self.static_children.remove(index).node)
     }
 
     pub fn set_dynamic_child(&mut self, param_name: &str, handler: Handler) {
         let child = DynamicTreeNode::new(handler, param_name);
 
-        self.dynamic_child = Some(Box::new(child));
+        self.dynamic_children.push(Box::new(child));
     }
 
-    pub fn delete_dynamic_child(&mut self) {
-        self.dynamic_child = None
+    pub fn delete_dynamic_child(&mut self, param_name: &str) {
+        self.dynamic_children
+            .retain(|child| child.param_name != param_name);
     }
 
-    pub fn set_wildcard_handler(&mut self, handler: Handler) {
-        self.wildcard_handler = handler
+    pub fn set_wildcard_handler(&mut self, handler: Handler, param_name: &str) {
+        self.wildcard_handler = handler;
+        self.wildcard_param_name = Some(param_name.to_string());
     }
 
     pub fn delete_wildcard_handler(&mut self) {
-        self.wildcard_handler = None
+        self.wildcard_handler = None;
+        self.wildcard_param_name = None;
     }
 
-    pub fn get_static_child(&self, segment: &str) -> Option<&StaticTreeNode> {
-        self.static_children.get(segment)
+    pub fn get_static_child(&self, label: &str) -> Option<&StaticTreeNode> {
+        self.static_children
+            .iter()
+            .find(|edge| edge.label == label)
+            .map(|edge| &edge.node)
     }
 
-    pub fn has_static_child(&self, segment: &str) -> bool {
-        self.static_children.contains_key(segment)
+    pub fn has_static_child(&self, label: &str) -> bool {
+        self.static_children.iter().any(|edge| edge.label == label)
     }
 
-    pub fn get_dynamic_child(&self) -> Option<&DynamicTreeNode> {
-        self.dynamic_child.as_ref().map(|n| n.as_ref())
+    pub fn get_dynamic_child(&self, param_name: &str) -> Option<&DynamicTreeNode> {
+        self.dynamic_children
+            .iter()
+            .find(|child| child.param_name == param_name)
+            .map(|n| n.as_ref())
     }
 
-    pub fn has_dynamic_child(&self) -> bool {
-        match self.dynamic_child {
-            Some(_) => true,
-            None => false,
-        }
+    pub fn has_dynamic_child(&self, param_name: &str) -> bool {
+        self.get_dynamic_child(param_name).is_some()
     }
 
-    pub fn get_child(&self, segment: &str) -> Option<TreeNode> {
-        self.get_static_child(segment)
-            .map(|n| TreeNode::Static(&n))
-            .or_else(|| self.get_dynamic_child().map(|n| TreeNode::Dynamic(n)))
+    pub fn get_child(&self, label: &str) -> Option<TreeNode> {
+        self.get_static_child(label)
+            .map(TreeNode::Static)
+            .or_else(|| self.dynamic_children.first().map(|n| TreeNode::Dynamic(n)))
     }
 
-    pub fn get_static_child_mut(&mut self, segment: &str) -> Option<&mut StaticTreeNode> {
-        self.static_children.get_mut(segment)
+    pub fn get_static_child_mut(&mut self, label: &str) -> Option<&mut StaticTreeNode> {
+        self.static_children
+            .iter_mut()
+            .find(|edge| edge.label == label)
+            .map(|edge| &mut edge.node)
     }
 
-    pub fn get_dynamic_child_mut(&mut self) -> Option<&mut DynamicTreeNode> {
-        self.dynamic_child.as_mut().map(|n| n.as_mut())
+    pub fn get_dynamic_child_mut(&mut self, param_name: &str) -> Option<&mut DynamicTreeNode> {
+        self.dynamic_children
+            .iter_mut()
+            .find(|child| child.param_name == param_name)
+            .map(|n| n.as_mut())
     }
 
-    pub fn get_child_mut(&mut self, segment: &str) -> Option<TreeNodeMut> {
+    pub fn get_child_mut(&mut self, label: &str) -> Option<TreeNodeMut> {
         let static_child = self
             .static_children
-            .get_mut(segment)
-            .map(|c| TreeNodeMut::Static(c));
+            .iter_mut()
+            .find(|edge| edge.label == label)
+            .map(|edge| TreeNodeMut::Static(&mut edge.node));
 
         if let Some(static_child) = static_child {
             Some(static_child)
         } else {
-            self.dynamic_child.as_mut().map(|n| TreeNodeMut::Dynamic(n))
+            self.dynamic_children
+                .first_mut()
+                .map(|n| TreeNodeMut::Dynamic(n))
+        }
+    }
+
+    /// Clears the handler `tokens` points at and reports whether one was
+    /// actually removed. Used by `RouterTree::remove`; callers prune any
+    /// child this leaves dead by checking `is_dead_leaf` on the way back
+    /// up the recursion.
+    fn remove(&mut self, tokens: &[PathToken]) -> bool {
+        match tokens.split_first() {
+            None => {
+                if self.handler.is_none() {
+                    return false;
+                }
+                self.handler = None;
+                true
+            }
+            Some((PathToken::Wildcard(_), _)) => {
+                if self.wildcard_handler.is_none() {
+                    return false;
+                }
+                self.delete_wildcard_handler();
+                true
+            }
+            Some((PathToken::Static(label), rest)) => self.remove_static(label, rest),
+            Some((PathToken::Dynamic(param_name), rest)) => {
+                let Some(dynamic) = self.get_dynamic_child_mut(param_name) else {
+                    return false;
+                };
+
+                let removed = dynamic.node.remove(rest);
+                if removed && dynamic.node.is_dead_leaf() {
+                    self.delete_dynamic_child(param_name);
+                }
+                removed
+            }
+        }
+    }
+
+    fn remove_static(&mut self, label: &str, rest: &[PathToken]) -> bool {
+        if label.is_empty() {
+            return self.remove(rest);
+        }
+
+        let Some(index) = self.find_edge_index(label) else {
+            return false;
+        };
+
+        if !label.starts_with(self.static_children[index].label.as_str()) {
+            return false;
+        }
+
+        let edge_label_len = self.static_children[index].label.len();
+        let removed = self.static_children[index]
+            .node
+            .remove_static(&label[edge_label_len..], rest);
+
+        if removed && self.static_children[index].node.is_dead_leaf() {
+            self.static_children.remove(index);
         }
+
+        removed
+    }
+
+    fn is_dead_leaf(&self) -> bool {
+        self.handler.is_none()
+            && self.wildcard_handler.is_none()
+            && self.static_children.is_empty()
+            && self.dynamic_children.is_empty()
     }
 }
 
+/// A static path, already split on `path_separator`, tagged with the
+/// dynamic segments that interrupt it. Consecutive static segments are
+/// kept joined (separator included) so they can be inserted into the
+/// radix tree as a single edge instead of one node per segment, and the
+/// separator leading into a following `Dynamic`/`Wildcard` token is kept
+/// on the static run too, since neither of those tokens consumes it.
+enum PathToken {
+    Static(String),
+    Dynamic(String),
+    Wildcard(String),
+}
+
 struct TraversePathReturn<'a> {
     node: &'a StaticTreeNode,
     params: Params,
+    // Which of `node`'s two handlers this match actually resolved to - a
+    // node can carry both an exact `handler` and a `wildcard_handler` (e.g.
+    // `/static/` alongside `/static/*filepath`), and only the traversal that
+    // produced `params` knows which one the match is for.
+    use_wildcard: bool,
 }
 
 impl TraversePathReturn<'_> {
     pub fn extract_handler(&self) -> Option<HandlerAndParams> {
-        self.node.handler.as_ref().map(|handler| HandlerAndParams {
+        let handler = if self.use_wildcard {
+            self.node.wildcard_handler.as_ref()?
+        } else {
+            self.node.handler.as_ref()?
+        };
+
+        Some(HandlerAndParams {
             handler: handler.clone(),
             params: serde_wasm_bindgen::to_value(&self.params).unwrap(),
         })
@@ -190,6 +436,7 @@ struct RouterTree {
     path_separator: String,
     param_prefix: String,
     wildcard_symbol: String,
+    decode_params: bool,
 }
 
 #[wasm_bindgen]
@@ -200,6 +447,7 @@ impl RouterTree {
         param_prefix: Option<String>,
         path_separator: Option<String>,
         wildcard_symbol: Option<String>,
+        decode_params: Option<bool>,
     ) -> Self {
         let root = StaticTreeNode::new(js_value_to_option(handler));
 
@@ -208,32 +456,68 @@ impl RouterTree {
             path_separator: path_separator.unwrap_or(PATH_SEPARATOR_DEFAULT.to_string()),
             param_prefix: param_prefix.unwrap_or(PARAM_PREFIX_DEFAULT.to_string()),
             wildcard_symbol: wildcard_symbol.unwrap_or(WILDCARD_SYMBOL_DEFAULT.to_string()),
+            decode_params: decode_params.unwrap_or(true),
         }
     }
 
     #[wasm_bindgen]
     pub fn add(&mut self, path: String, handler: JsValue) {
-        let segments = self.parse_path(&path);
-        let param_prefix = self.param_prefix.as_str();
-        let mut current_node = &mut self.root;
+        self.add_internal(path, handler, HashMap::new());
+    }
 
-        for segment in segments {
-            current_node = if RouterTree::is_dynamic_segment(segment, param_prefix) {
-                let param_name = RouterTree::strip_param_prefix(segment, param_prefix);
+    /// Like `add`, but `constraints` (a JS object or `Map` from param name
+    /// to either a built-in kind string `"int" | "uuid" | "alpha"` or a
+    /// predicate function) restricts which values a dynamic segment is
+    /// allowed to bind, so e.g. `/users/:id` and `/users/:name` can be
+    /// registered as distinct routes.
+    #[wasm_bindgen]
+    pub fn add_with_constraints(&mut self, path: String, handler: JsValue, constraints: JsValue) {
+        let raw_constraints: HashMap<String, JsValue> =
+            serde_wasm_bindgen::from_value(constraints).unwrap_or_default();
+        let constraints = raw_constraints
+            .into_iter()
+            .filter_map(|(name, value)| Constraint::from_js(&value).map(|c| (name, c)))
+            .collect();
+
+        self.add_internal(path, handler, constraints);
+    }
 
-                if !current_node.has_dynamic_child() {
-                    current_node.set_dynamic_child(param_name, None);
+    fn add_internal(
+        &mut self,
+        path: String,
+        handler: JsValue,
+        mut constraints: HashMap<String, Constraint>,
+    ) {
+        let tokens = self.tokenize_path(&path);
+        let mut current_node = &mut self.root;
+        let mut wildcard_param_name: Option<String> = None;
+
+        for token in tokens {
+            current_node = match token {
+                PathToken::Static(label) => current_node.insert_static_path(&label),
+                PathToken::Dynamic(param_name) => {
+                    if !current_node.has_dynamic_child(&param_name) {
+                        current_node.set_dynamic_child(&param_name, None);
+                    }
+                    let dynamic = current_node.get_dynamic_child_mut(&param_name).unwrap();
+                    if let Some(constraint) = constraints.remove(&param_name) {
+                        dynamic.set_constraint(Some(constraint));
+                    }
+                    &mut dynamic.node
                 }
-                &mut current_node.get_dynamic_child_mut().unwrap().node
-            } else {
-                if !current_node.has_static_child(segment) {
-                    current_node.add_static_child(segment, None);
+                PathToken::Wildcard(param_name) => {
+                    wildcard_param_name = Some(param_name);
+                    current_node
                 }
-                current_node.get_static_child_mut(segment).unwrap()
             }
         }
 
-        current_node.handler = js_value_to_option(handler);
+        match wildcard_param_name {
+            Some(param_name) => {
+                current_node.set_wildcard_handler(js_value_to_option(handler), &param_name)
+            }
+            None => current_node.handler = js_value_to_option(handler),
+        }
     }
 
     #[wasm_bindgen]
@@ -243,35 +527,195 @@ impl RouterTree {
             .flatten()
     }
 
+    /// Removes the route registered at `path`, pruning any ancestor nodes
+    /// this leaves with no handler, no wildcard handler, and no children.
+    /// Returns whether a route was actually removed.
+    #[wasm_bindgen]
+    pub fn remove(&mut self, path: String) -> bool {
+        let tokens = self.tokenize_path(&path);
+
+        self.root.remove(&tokens)
+    }
+
     fn traverse_path(&self, path: &String) -> Option<TraversePathReturn> {
-        let segments = self.parse_path(path);
-        let mut params: Params = HashMap::new();
-        let mut current_node = &self.root;
+        let remaining = path.trim_start_matches(&self.path_separator);
+
+        Self::traverse_node(
+            &self.root,
+            remaining,
+            &self.path_separator,
+            self.decode_params,
+        )
+    }
 
-        for segment in segments {
-            if current_node.wildcard_handler.is_some() {
-                break;
+    /// Backtracking search over a single node: try the static edge first,
+    /// then the dynamic child, and only fall back to this node's catch-all
+    /// if neither higher-priority branch resolves to a handler further
+    /// down. Each branch builds its own `params` map, so a static attempt
+    /// that dead-ends can never leak a binding into the dynamic attempt.
+    fn traverse_node<'a>(
+        node: &'a StaticTreeNode,
+        remaining: &str,
+        path_separator: &str,
+        decode_params: bool,
+    ) -> Option<TraversePathReturn<'a>> {
+        if remaining.is_empty() && node.handler.is_some() {
+            return Some(TraversePathReturn {
+                node,
+                params: HashMap::new(),
+                use_wildcard: false,
+            });
+        }
+
+        if !remaining.is_empty() {
+            if let Some(edge) = node
+                .static_children
+                .iter()
+                .find(|edge| remaining.starts_with(edge.label.as_str()))
+            {
+                if let Some(result) = Self::traverse_node(
+                    &edge.node,
+                    &remaining[edge.label.len()..],
+                    path_separator,
+                    decode_params,
+                ) {
+                    return Some(result);
+                }
             }
-            if let Some(child) = current_node.get_child(segment) {
-                match child {
-                    TreeNode::Static(node) => current_node = node,
-                    TreeNode::Dynamic(node) => {
-                        params.insert(node.param_name.clone(), segment.to_string());
-                        current_node = &node.node;
+
+            if !node.dynamic_children.is_empty() {
+                let (segment, rest) = Self::split_segment(remaining, path_separator);
+
+                // Constrained candidates are tried before the unconstrained
+                // catch-all param, so e.g. `/users/:id` (int) wins over a
+                // sibling `/users/:name` for a purely numeric segment.
+                let mut candidates: Vec<&DynamicTreeNode> =
+                    node.dynamic_children.iter().map(|c| c.as_ref()).collect();
+                candidates.sort_by_key(|dynamic| dynamic.constraint.is_none());
+
+                for dynamic in candidates {
+                    let satisfies_constraint = match &dynamic.constraint {
+                        Some(constraint) => constraint.matches(segment),
+                        None => true,
+                    };
+
+                    if segment.is_empty() || !satisfies_constraint {
+                        continue;
+                    }
+
+                    if let Some(mut result) =
+                        Self::traverse_node(&dynamic.node, rest, path_separator, decode_params)
+                    {
+                        let value = Self::bind_param_value(segment, decode_params);
+                        result.params.insert(dynamic.param_name.clone(), value);
+                        return Some(result);
                     }
                 }
+            }
+        }
+
+        if node.wildcard_handler.is_some() {
+            let mut params = HashMap::new();
+            if let Some(param_name) = &node.wildcard_param_name {
+                // The static edge leading here already owns the separator
+                // that sits between it and the catch-all; this strip is
+                // just a defensive guard against an unconsumed one.
+                let capture = remaining.strip_prefix(path_separator).unwrap_or(remaining);
+                params.insert(
+                    param_name.clone(),
+                    Self::bind_param_value(capture, decode_params),
+                );
+            }
+            return Some(TraversePathReturn {
+                node,
+                params,
+                use_wildcard: true,
+            });
+        }
+
+        None
+    }
+
+    fn split_segment<'a>(remaining: &'a str, path_separator: &str) -> (&'a str, &'a str) {
+        match remaining.find(path_separator) {
+            Some(index) => (
+                &remaining[..index],
+                &remaining[index + path_separator.len()..],
+            ),
+            None => (remaining, ""),
+        }
+    }
+
+    /// Applies percent-decoding to a captured param value, unless the
+    /// caller opted out via the `decode_params` constructor flag. Invalid
+    /// percent-encoded UTF-8 falls back to the lossy decoding rather than
+    /// failing the match.
+    fn bind_param_value(raw: &str, decode_params: bool) -> String {
+        if !decode_params {
+            return raw.to_string();
+        }
+
+        percent_encoding::percent_decode_str(raw)
+            .decode_utf8_lossy()
+            .into_owned()
+    }
+
+    /// Splits `path` on `path_separator` and re-groups it into a sequence
+    /// of static runs (joined back together with the separator), single
+    /// dynamic segments, and a trailing catch-all, so the radix insert only
+    /// ever sees one static label per run of non-dynamic segments. A
+    /// catch-all segment (e.g. `*filepath`) ends the sequence: anything
+    /// registered after it in the path is meaningless and ignored.
+    fn tokenize_path(&self, path: &str) -> Vec<PathToken> {
+        let param_prefix = self.param_prefix.as_str();
+        let wildcard_symbol = self.wildcard_symbol.as_str();
+        let mut tokens = Vec::new();
+        let mut static_run = String::new();
+
+        for segment in self.parse_path(path) {
+            if RouterTree::is_wildcard_segment(segment, wildcard_symbol) {
+                if !static_run.is_empty() {
+                    // The separator between the static run and this
+                    // catch-all is still part of the path being matched,
+                    // so it has to live on the static edge - nothing
+                    // downstream of a Wildcard token consumes separators.
+                    static_run.push_str(&self.path_separator);
+                    tokens.push(PathToken::Static(std::mem::take(&mut static_run)));
+                }
+                tokens.push(PathToken::Wildcard(
+                    RouterTree::strip_wildcard_symbol(segment, wildcard_symbol).to_string(),
+                ));
+                break;
+            }
+
+            if RouterTree::is_dynamic_segment(segment, param_prefix) {
+                if !static_run.is_empty() {
+                    // Same reasoning as above: the separator before a
+                    // dynamic segment is consumed by the static edge, not
+                    // by the dynamic child, which only ever sees the
+                    // segment's own characters.
+                    static_run.push_str(&self.path_separator);
+                    tokens.push(PathToken::Static(std::mem::take(&mut static_run)));
+                }
+                tokens.push(PathToken::Dynamic(
+                    RouterTree::strip_param_prefix(segment, param_prefix).to_string(),
+                ));
             } else {
-                return None;
+                if !static_run.is_empty() {
+                    static_run.push_str(&self.path_separator);
+                }
+                static_run.push_str(segment);
             }
         }
 
-        return Some(TraversePathReturn {
-            node: current_node,
-            params,
-        });
+        if !static_run.is_empty() {
+            tokens.push(PathToken::Static(static_run));
+        }
+
+        tokens
     }
 
-    fn parse_path<'a>(&self, path: &'a String) -> Vec<&'a str> {
+    fn parse_path<'a>(&self, path: &'a str) -> Vec<&'a str> {
         path.trim_start_matches(&self.path_separator)
             .split(&self.path_separator)
             .collect()
@@ -288,6 +732,14 @@ impl RouterTree {
     fn strip_param_prefix<'a>(segment: &'a str, param_prefix: &str) -> &'a str {
         segment.strip_prefix(param_prefix).unwrap_or("")
     }
+
+    fn is_wildcard_segment(segment: &str, wildcard_symbol: &str) -> bool {
+        segment.starts_with(wildcard_symbol)
+    }
+
+    fn strip_wildcard_symbol<'a>(segment: &'a str, wildcard_symbol: &str) -> &'a str {
+        segment.strip_prefix(wildcard_symbol).unwrap_or("")
+    }
 }
 
 #[cfg(test)]
@@ -296,14 +748,150 @@ mod tests {
 
     #[test]
     fn it_works() {
-        let mut router = RouterTree::new(JsValue::null(), None, None, None);
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        router.add("/user/:id".to_string(), JsValue::from_str("user"));
+
+        let result = router
+            .get("/user/123".to_string())
+            .expect("/user/:id should match /user/123");
+
+        assert_eq!(result.handler().as_string().as_deref(), Some("user"));
+    }
+
+    #[test]
+    fn splits_static_edges_diverging_inside_a_multibyte_codepoint() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        // "é" and "è" share the UTF-8 lead byte 0xC3 but diverge in the
+        // second byte, so a byte-only common-prefix count would land the
+        // split mid-codepoint.
+        router.add("/aé".to_string(), JsValue::from_str("e_acute"));
+        router.add("/aè".to_string(), JsValue::from_str("e_grave"));
+
+        let acute = router
+            .get("/aé".to_string())
+            .expect("/aé should match its own route");
+        assert_eq!(acute.handler().as_string().as_deref(), Some("e_acute"));
+
+        let grave = router
+            .get("/aè".to_string())
+            .expect("/aè should match its own route");
+        assert_eq!(grave.handler().as_string().as_deref(), Some("e_grave"));
+    }
+
+    #[test]
+    fn backtracks_from_a_static_dead_end_into_a_dynamic_sibling() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        router.add("/files/list".to_string(), JsValue::from_str("list"));
+        router.add("/files/:id/extra".to_string(), JsValue::from_str("extra"));
+
+        let list = router
+            .get("/files/list".to_string())
+            .expect("/files/list should still match its own static route");
+        assert_eq!(list.handler().as_string().as_deref(), Some("list"));
+
+        let extra = router
+            .get("/files/list/extra".to_string())
+            .expect("/files/list/extra should backtrack into /files/:id/extra");
+        assert_eq!(extra.handler().as_string().as_deref(), Some("extra"));
+    }
+
+    #[test]
+    fn catch_all_captures_remainder_without_leading_separator() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        router.add("/static/*filepath".to_string(), JsValue::from_str("assets"));
+
+        let result = router
+            .get("/static/css/app.css".to_string())
+            .expect("/static/*filepath should match /static/css/app.css");
+        let params: HashMap<String, String> =
+            serde_wasm_bindgen::from_value(result.params()).unwrap();
+
+        assert_eq!(params.get("filepath"), Some(&"css/app.css".to_string()));
+    }
+
+    #[test]
+    fn wildcard_match_does_not_fall_back_to_the_sibling_exact_handler() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        router.add("/static/".to_string(), JsValue::from_str("index"));
+        router.add("/static/*filepath".to_string(), JsValue::from_str("assets"));
+
+        let result = router
+            .get("/static/app.css".to_string())
+            .expect("/static/*filepath should match /static/app.css");
+
+        assert_eq!(result.handler().as_string().as_deref(), Some("assets"));
+
+        let params: HashMap<String, String> =
+            serde_wasm_bindgen::from_value(result.params()).unwrap();
+        assert_eq!(params.get("filepath"), Some(&"app.css".to_string()));
+    }
+
+    #[test]
+    fn disambiguates_constrained_dynamic_siblings_by_param_name() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        let id_constraints = js_sys::Object::new();
+        js_sys::Reflect::set(&id_constraints, &"id".into(), &"int".into()).unwrap();
+        router.add_with_constraints(
+            "/users/:id".to_string(),
+            JsValue::from_str("by_id"),
+            id_constraints.into(),
+        );
+
+        let name_constraints = js_sys::Object::new();
+        js_sys::Reflect::set(&name_constraints, &"name".into(), &"alpha".into()).unwrap();
+        router.add_with_constraints(
+            "/users/:name".to_string(),
+            JsValue::from_str("by_name"),
+            name_constraints.into(),
+        );
+
+        let by_id = router
+            .get("/users/123".to_string())
+            .expect("/users/:id should match the numeric segment");
+        assert_eq!(by_id.handler().as_string().as_deref(), Some("by_id"));
+
+        let by_name = router
+            .get("/users/alice".to_string())
+            .expect("/users/:name should match the alphabetic segment");
+        assert_eq!(by_name.handler().as_string().as_deref(), Some("by_name"));
+    }
+
+    #[test]
+    fn percent_decodes_captured_param_values() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
+
+        router.add("/search/:term".to_string(), JsValue::from_str("search"));
+
+        let result = router
+            .get("/search/hello%20world".to_string())
+            .expect("/search/:term should match the encoded segment");
+        let params: HashMap<String, String> =
+            serde_wasm_bindgen::from_value(result.params()).unwrap();
+
+        assert_eq!(params.get("term"), Some(&"hello world".to_string()));
+    }
+
+    #[test]
+    fn remove_prunes_dead_branches_but_keeps_live_siblings() {
+        let mut router = RouterTree::new(JsValue::null(), None, None, None, None);
 
-        router.add("/user/:id".to_string(), JsValue::null());
+        router.add("/files/list".to_string(), JsValue::from_str("list"));
+        router.add("/files/:id/extra".to_string(), JsValue::from_str("extra"));
 
-        let result = router.get("/user/123".to_string());
+        assert!(router.remove("/files/:id/extra".to_string()));
+        assert!(router.get("/files/list/extra".to_string()).is_none());
 
-        dbg!("I am here!!!");
+        let list = router
+            .get("/files/list".to_string())
+            .expect("removing the dynamic branch must not affect the static sibling");
+        assert_eq!(list.handler().as_string().as_deref(), Some("list"));
 
-        assert_eq!(1, 1)
+        assert!(!router.remove("/files/:id/extra".to_string()));
     }
 }